@@ -1,15 +1,57 @@
+use std::collections::VecDeque;
+use std::env;
+use std::fs;
 use std::io::{stdout, Stdout};
 use std::ops::Range;
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread;
+use std::time::Duration;
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use crossterm::cursor::{self, MoveToNextLine};
-use crossterm::event::{read, Event, KeyCode, KeyModifiers};
+use crossterm::event::{self, read, DisableBracketedPaste, EnableBracketedPaste, Event, KeyCode, KeyEvent, KeyModifiers};
 use crossterm::style::{Print, Stylize};
 use crossterm::terminal::{self, Clear, ClearType};
 use crossterm::{execute, queue};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 use rush_state::shell::Context;
 
+// How often the terminal-event thread polls for the next event. Short enough that a resize
+// or an out-of-band message (see `Console::message_sender`) is noticed promptly
+const EVENT_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+// Polls for terminal events on a dedicated thread and forwards them over a channel, so
+// `Console::read` never blocks on crossterm's blocking `read()` and can react to other
+// sources (a resize, an out-of-band message) while waiting for the next keypress
+// * This thread is only safe to run alongside the REPL because `Console` never calls
+// * `cursor::position()`: crossterm serializes `read()`/`poll()`/`position()` through one
+// * internal event reader and stdin source, so a synchronous position query from the REPL
+// * would contend with this thread parked in `read()` for the same lock. Cursor math instead
+// * tracks its own `display_col` (see that field's doc on `Console`)
+fn spawn_event_thread() -> Receiver<Event> {
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || loop {
+        match event::poll(EVENT_POLL_INTERVAL) {
+            Ok(true) => match read() {
+                Ok(terminal_event) => {
+                    if tx.send(terminal_event).is_err() {
+                        return;
+                    }
+                }
+                Err(_) => return,
+            },
+            Ok(false) => continue,
+            Err(_) => return,
+        }
+    });
+
+    rx
+}
+
 // Represents an action that the handler instructs the REPL (Console.read()) to perform
 // Allows for some actions to be performed in the handler and some to be performed in the REPL
 enum ReplAction {
@@ -23,6 +65,343 @@ enum ReplAction {
     Ignore,
 }
 
+// Persists submitted lines across sessions in a `~/.rush_history` file
+// Loaded once on Console::new() and appended to every time a line is returned from read()
+struct History {
+    entries: VecDeque<String>,
+    path: PathBuf,
+}
+
+impl History {
+    const MAX_ENTRIES: usize = 1000;
+
+    fn load() -> Self {
+        let path = history_path();
+        let entries = fs::read_to_string(&path)
+            .map(|contents| contents.lines().map(|line| line.replace('␤', "\n")).collect())
+            .unwrap_or_default();
+
+        Self { entries, path }
+    }
+
+    // Returns the suffix of the most recent entry that starts with `prefix`, for inline hinting
+    fn suggestion(&self, prefix: &str) -> Option<String> {
+        if prefix.is_empty() {
+            return None;
+        }
+
+        self.entries
+            .iter()
+            .rev()
+            .find(|entry| entry.starts_with(prefix) && entry.len() > prefix.len())
+            .map(|entry| entry[prefix.len()..].to_string())
+    }
+
+    // Appends a line to history unless it duplicates the most recent entry, then persists to disk
+    fn push(&mut self, line: String) {
+        if line.is_empty() || self.entries.back() == Some(&line) {
+            return;
+        }
+
+        self.entries.push_back(line);
+        if self.entries.len() > Self::MAX_ENTRIES {
+            self.entries.pop_front();
+        }
+
+        // * Best-effort: a failure to persist history should never interrupt the shell
+        let _ = self.save();
+    }
+
+    // Entries are joined with `\n` as the record separator, so any newline embedded in an
+    // entry itself (e.g. a multi-line command accepted from a bracketed paste) is escaped to
+    // the same placeholder used elsewhere for display, and restored by `load()`
+    fn save(&self) -> Result<()> {
+        let contents = self
+            .entries
+            .iter()
+            .map(|entry| entry.replace('\n', "␤"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        fs::write(&self.path, contents)?;
+        Ok(())
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn get(&self, index: usize) -> Option<&String> {
+        self.entries.get(index)
+    }
+
+    // Finds the most recent entry before `before` whose text contains `pattern`
+    fn search_before(&self, before: usize, pattern: &str) -> Option<usize> {
+        if pattern.is_empty() {
+            return None;
+        }
+
+        self.entries
+            .iter()
+            .enumerate()
+            .take(before)
+            .rev()
+            .find(|(_, entry)| entry.contains(pattern))
+            .map(|(index, _)| index)
+    }
+}
+
+fn history_path() -> PathBuf {
+    let home = env::var("HOME").unwrap_or_else(|_| ".".to_string());
+    PathBuf::from(home).join(".rush_history")
+}
+
+// State for an in-progress Ctrl-R incremental reverse history search
+// The original line buffer is kept so editing can fall back to it if the search is cancelled
+struct ReverseSearch {
+    pattern: String,
+    match_index: Option<usize>,
+    original_line: String,
+}
+
+// Built-in shell commands offered alongside `$PATH` executables when completing the first word
+const BUILTINS: &[&str] = &["cd", "exit", "export", "alias", "history", "help"];
+
+// Completes a word in the line buffer, returning the byte index the candidates replace from
+trait Completer {
+    fn complete(&self, line: &str, pos: usize) -> (usize, Vec<String>);
+}
+
+// Completes builtins/`$PATH` executables for the first word and filesystem paths for the rest
+struct DefaultCompleter {
+    cwd: PathBuf,
+}
+
+impl DefaultCompleter {
+    fn new(cwd: PathBuf) -> Self {
+        Self { cwd }
+    }
+
+    fn complete_command(&self, word: &str) -> Vec<String> {
+        let mut candidates: Vec<String> = BUILTINS
+            .iter()
+            .filter(|name| name.starts_with(word))
+            .map(|name| name.to_string())
+            .collect();
+
+        if let Ok(path_var) = env::var("PATH") {
+            for dir in env::split_paths(&path_var) {
+                let Ok(entries) = fs::read_dir(&dir) else { continue };
+                for entry in entries.flatten() {
+                    if let Some(name) = entry.file_name().to_str() {
+                        if name.starts_with(word) && !candidates.iter().any(|c| c == name) {
+                            candidates.push(name.to_string());
+                        }
+                    }
+                }
+            }
+        }
+
+        candidates.sort();
+        candidates
+    }
+
+    fn complete_path(&self, word: &str) -> Vec<String> {
+        let (dir, prefix) = match word.rfind('/') {
+            Some(i) => (word[..=i].to_string(), word[i + 1..].to_string()),
+            None => (String::new(), word.to_string()),
+        };
+
+        let search_dir = if dir.is_empty() {
+            self.cwd.clone()
+        } else if dir.starts_with('/') {
+            PathBuf::from(&dir)
+        } else {
+            self.cwd.join(&dir)
+        };
+
+        let Ok(entries) = fs::read_dir(&search_dir) else { return Vec::new() };
+
+        let mut candidates: Vec<String> = entries
+            .flatten()
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .filter(|name| name.starts_with(&prefix))
+            .map(|name| format!("{}{}", dir, name))
+            .collect();
+
+        candidates.sort();
+        candidates
+    }
+}
+
+impl Completer for DefaultCompleter {
+    fn complete(&self, line: &str, pos: usize) -> (usize, Vec<String>) {
+        let before_cursor = &line[..pos];
+        let word_start = before_cursor.rfind(' ').map(|i| i + 1).unwrap_or(0);
+        let word = &line[word_start..pos];
+        let is_first_word = before_cursor[..word_start].trim().is_empty();
+
+        let candidates = if is_first_word {
+            self.complete_command(word)
+        } else {
+            self.complete_path(word)
+        };
+
+        (word_start, candidates)
+    }
+}
+
+// Returns the longest common prefix shared by every candidate, or an empty string if there is none
+fn longest_common_prefix(candidates: &[String]) -> String {
+    let mut prefix = match candidates.first() {
+        Some(first) => first.clone(),
+        None => return String::new(),
+    };
+
+    for candidate in &candidates[1..] {
+        while !candidate.starts_with(&prefix) {
+            prefix.pop();
+        }
+    }
+
+    prefix
+}
+
+// Tracks the candidates offered by the previous Tab press, so a second consecutive press that
+// computes the same candidates prints the full candidate list
+struct CompletionState {
+    start: usize,
+    candidates: Vec<String>,
+}
+
+// The direction a kill was made in, used to decide whether consecutive kills should
+// append to the same ring slot instead of pushing a new one
+#[derive(PartialEq, Eq, Clone, Copy)]
+enum KillDirection {
+    Backward,
+    Forward,
+}
+
+// A bounded ring of killed text supporting Ctrl-Y yank and Alt-Y yank-pop, Emacs-style
+struct KillRing {
+    slots: VecDeque<String>,
+    last_kill: Option<KillDirection>,
+}
+
+impl KillRing {
+    const MAX_SLOTS: usize = 16;
+
+    fn new() -> Self {
+        Self {
+            slots: VecDeque::new(),
+            last_kill: None,
+        }
+    }
+
+    // Records killed text, appending to the current slot if the previous kill was the same
+    // direction, otherwise pushing a new slot onto the ring
+    fn kill(&mut self, text: String, direction: KillDirection) {
+        if text.is_empty() {
+            return;
+        }
+
+        if self.last_kill == Some(direction) {
+            if let Some(slot) = self.slots.back_mut() {
+                match direction {
+                    KillDirection::Backward => slot.insert_str(0, &text),
+                    KillDirection::Forward => slot.push_str(&text),
+                }
+                self.last_kill = Some(direction);
+                return;
+            }
+        }
+
+        self.slots.push_back(text);
+        if self.slots.len() > Self::MAX_SLOTS {
+            self.slots.pop_front();
+        }
+        self.last_kill = Some(direction);
+    }
+}
+
+// Finds the start of the word immediately before `pos`, skipping any trailing whitespace
+fn word_start_before(buffer: &str, pos: usize) -> usize {
+    let bytes = buffer.as_bytes();
+    let mut index = pos;
+
+    while index > 0 && bytes[index - 1].is_ascii_whitespace() {
+        index -= 1;
+    }
+    while index > 0 && !bytes[index - 1].is_ascii_whitespace() {
+        index -= 1;
+    }
+
+    index
+}
+
+// Finds the end of the word immediately after `pos`, skipping any leading whitespace
+fn word_end_after(buffer: &str, pos: usize) -> usize {
+    let bytes = buffer.as_bytes();
+    let mut index = pos;
+
+    while index < bytes.len() && bytes[index].is_ascii_whitespace() {
+        index += 1;
+    }
+    while index < bytes.len() && !bytes[index].is_ascii_whitespace() {
+        index += 1;
+    }
+
+    index
+}
+
+// Returns the byte offset where the grapheme cluster immediately before `byte_pos` starts
+fn prev_grapheme_boundary(line: &str, byte_pos: usize) -> usize {
+    line[..byte_pos]
+        .grapheme_indices(true)
+        .next_back()
+        .map(|(i, _)| i)
+        .unwrap_or(0)
+}
+
+// Returns the byte offset immediately after the grapheme cluster that starts at `byte_pos`
+fn next_grapheme_boundary(line: &str, byte_pos: usize) -> usize {
+    line[byte_pos..]
+        .grapheme_indices(true)
+        .nth(1)
+        .map(|(i, _)| byte_pos + i)
+        .unwrap_or(line.len())
+}
+
+// The terminal column width of the text between two byte offsets, at least 1 so a cursor
+// move is never a no-op
+fn display_width(line: &str, start: usize, end: usize) -> usize {
+    UnicodeWidthStr::width(&line[start..end]).max(1)
+}
+
+// The terminal column width of `text`, skipping over ANSI/CSI escape sequences (as emitted by
+// crossterm's `Stylize`) so a colored prompt segment doesn't inflate the computed width
+fn visible_width(text: &str) -> usize {
+    let mut width = 0;
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            // Skip the `[` introducer first: it lies in the final-byte range itself
+            if chars.next() == Some('[') {
+                for c in chars.by_ref() {
+                    if ('\x40'..='\x7e').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            continue;
+        }
+
+        width += UnicodeWidthChar::width(c).unwrap_or(0);
+    }
+
+    width
+}
+
 // Allows for reading a line of input from the user through the .read() method
 // Handles all the actual terminal interaction between when the method is invoked and
 // when the command is actually returned, such as line buffering etc
@@ -32,29 +411,79 @@ pub struct Console {
     // A string that stores the current line of input
     // When the user hits ENTER, the line buffer is returned to the shell
     line_buffer: String,
-    // The "coordinate" of the cursor is a one-dimensional index of the cursor in the buffer
+    // The "coordinate" of the cursor is a byte offset into `line_buffer`, always aligned to
+    // a grapheme cluster boundary so multibyte and wide glyphs never split mid-character
     cursor_coord: usize,
+    // The terminal column the real cursor is currently at, tracked internally rather than
+    // queried from the terminal: crossterm serializes `cursor::position()` through the same
+    // internal event reader that `spawn_event_thread` parks in, so querying it while that
+    // thread holds the lock would contend, block, or read stale data
+    display_col: u16,
+    // The display width of the prompt's own row (the column the buffer starts at), refreshed
+    // every time `print_prompt` runs
+    prompt_width: usize,
+    // Previously submitted lines, persisted across sessions
+    history: History,
+    // Index into `history` of the entry currently loaded into the buffer, if any
+    history_cursor: Option<usize>,
+    // Present while a Ctrl-R reverse-i-search is active
+    search: Option<ReverseSearch>,
+    // Candidates offered by the most recent Tab press, kept to detect a second consecutive press
+    completion: Option<CompletionState>,
+    // Ring of killed text for Ctrl-W/Alt-D/Ctrl-U/Ctrl-K to feed and Ctrl-Y to yank from
+    kill_ring: KillRing,
+    // The (start, end, ring_index) of the most recent yank, kept only until the next key other
+    // than Ctrl-Y/Alt-Y (or a paste) so Alt-Y knows what to replace
+    last_yank: Option<(usize, usize, usize)>,
+    // The suffix of a matching history entry, shown dimmed after the cursor at end-of-line
+    hint: Option<String>,
+    // Receives terminal events forwarded by the dedicated event-reader thread
+    event_rx: Receiver<Event>,
+    // Receives out-of-band messages pushed by other parts of the shell via `message_sender`
+    message_rx: Receiver<String>,
+    // Cloned and handed out by `message_sender`; kept so a fresh clone is always available
+    message_tx: Sender<String>,
 }
 
 impl Console {
     pub fn new() -> Self {
+        let (message_tx, message_rx) = mpsc::channel();
+
         Self {
             stdout: stdout(),
             line_buffer: String::new(),
             cursor_coord: 0,
+            display_col: 0,
+            prompt_width: 0,
+            history: History::load(),
+            history_cursor: None,
+            search: None,
+            completion: None,
+            kill_ring: KillRing::new(),
+            last_yank: None,
+            hint: None,
+            event_rx: spawn_event_thread(),
+            message_rx,
+            message_tx,
         }
     }
 
+    // Returns a sender that other parts of the shell can use to push an out-of-band message
+    // (e.g. a background job completion notice) to be printed above the prompt
+    pub fn message_sender(&self) -> Sender<String> {
+        self.message_tx.clone()
+    }
+
     // TODO: Map crossterm errors to custom errors
     // Prompts the user and handles all input keypresses/resulting terminal interaction up until a line of input is entered
     pub fn read(&mut self, context: &Context) -> Result<String> {
         terminal::enable_raw_mode()?;
+        execute!(self.stdout, EnableBracketedPaste)?;
         self.print_prompt(context)?;
+        execute!(self.stdout)?;
 
         loop {
-            execute!(self.stdout)?;
-            let event = read()?;
-            let action = self.handle_event(event)?;
+            let action = self.next_action(context)?;
 
             // self.print_debug_text(1, format!("Raw buffer: {}", self.line_buffer))?;
             // self.print_debug_text(1, format!("Terminal X size: {} | Terminal Y size: {}", terminal::size()?.0, terminal::size()?.1))?;
@@ -62,23 +491,31 @@ impl Console {
 
             match action {
                 ReplAction::Return => {
-                    execute!(self.stdout, MoveToNextLine(1))?;
+                    execute!(self.stdout, MoveToNextLine(1), DisableBracketedPaste)?;
                     terminal::disable_raw_mode()?;
                     let line = self.line_buffer.clone();
                     self.line_buffer.clear();
                     self.cursor_coord = 0;
+                    self.history_cursor = None;
+                    self.search = None;
+                    self.hint = None;
+                    self.history.push(line.clone());
                     self.clear_debug_text(1..2)?;
                     return Ok(line);
                 }
                 ReplAction::Clear => {
                     self.line_buffer.clear();
                     self.cursor_coord = 0;
+                    self.history_cursor = None;
+                    self.search = None;
+                    self.hint = None;
                     self.clear_terminal()?;
                     self.print_prompt(context)?;
+                    execute!(self.stdout)?;
                 }
                 ReplAction::Exit => {
                     self.clear_terminal()?;
-                    execute!(self.stdout)?;
+                    execute!(self.stdout, DisableBracketedPaste)?;
                     terminal::disable_raw_mode()?;
                     std::process::exit(0);
                 }
@@ -87,14 +524,121 @@ impl Console {
         }
     }
 
+    // Waits for the next terminal event forwarded by the event-reader thread, printing any
+    // out-of-band messages that arrive in the meantime above the prompt
+    fn next_action(&mut self, context: &Context) -> Result<ReplAction> {
+        loop {
+            if let Ok(message) = self.message_rx.try_recv() {
+                self.print_message_above_prompt(&message, context)?;
+                execute!(self.stdout)?;
+                continue;
+            }
+
+            match self.event_rx.recv_timeout(EVENT_POLL_INTERVAL) {
+                Ok(event) => {
+                    let action = self.handle_event(event, context)?;
+                    execute!(self.stdout)?;
+                    return Ok(action);
+                }
+                Err(RecvTimeoutError::Timeout) => continue,
+                Err(RecvTimeoutError::Disconnected) => return Err(anyhow!("terminal event reader thread disconnected")),
+            }
+        }
+    }
+
+    // Prints a message above the prompt, then redraws the prompt and line buffer beneath it
+    fn print_message_above_prompt(&mut self, message: &str, context: &Context) -> Result<()> {
+        queue!(self.stdout, cursor::MoveToColumn(0), Clear(ClearType::CurrentLine), Print(message), MoveToNextLine(1))?;
+        self.print_prompt(context)?;
+        queue!(self.stdout, Print(self.line_buffer.replace('\n', "␤")))?;
+        self.reposition_cursor_after_redraw()?;
+
+        Ok(())
+    }
+
+    // Redraws the prompt and buffer after a resize, since the previous wrap positions may no
+    // longer be valid at the new terminal width. Clears the whole terminal rather than just the
+    // current row, since the buffer may previously have wrapped onto rows below it that would
+    // otherwise linger on screen
+    fn redraw_after_resize(&mut self, context: &Context) -> Result<()> {
+        self.clear_terminal()?;
+        self.print_prompt(context)?;
+        queue!(self.stdout, Print(self.line_buffer.replace('\n', "␤")))?;
+        self.reposition_cursor_after_redraw()?;
+
+        Ok(())
+    }
+
+    // Redraws the prompt and the full line buffer from column 0, then repositions the cursor.
+    // Used when ending a reverse-i-search, which has overwritten the prompt line with the
+    // `(reverse-i-search)...` text and left `cursor_coord` out of sync with it
+    fn redraw_line(&mut self, context: &Context) -> Result<()> {
+        queue!(self.stdout, cursor::MoveToColumn(0), Clear(ClearType::CurrentLine))?;
+        self.print_prompt(context)?;
+        queue!(self.stdout, Print(self.line_buffer.replace('\n', "␤")))?;
+        self.reposition_cursor_after_redraw()?;
+
+        Ok(())
+    }
+
+    // After printing the full line buffer, the real terminal cursor sits at its end; recomputes
+    // `display_col` for that position and walks back to `cursor_coord` so the bookkeeping
+    // position and the real cursor agree again
+    fn reposition_cursor_after_redraw(&mut self) -> Result<()> {
+        let target = self.cursor_coord;
+        self.cursor_coord = self.line_buffer.len();
+        self.recompute_display_col()?;
+        self.move_cursor_left_to(target)
+    }
+
     // Handles a key event by queueing appropriate commands based on the given keypress
     // * The bool is essentially a "should return" flag. This will be changed in the future.
     // TODO: Change this return type
-    fn handle_event(&mut self, event: Event) -> Result<ReplAction> {
+    fn handle_event(&mut self, event: Event, context: &Context) -> Result<ReplAction> {
+        if let Event::Resize(_, _) = event {
+            self.redraw_after_resize(context)?;
+            return Ok(ReplAction::Ignore);
+        }
+
+        if let Event::Paste(text) = event {
+            self.handle_paste(&text)?;
+            return Ok(ReplAction::Ignore);
+        }
+
         if let Event::Key(event) = event {
+            if self.search.is_some() {
+                return self.handle_search_event(event, context);
+            }
+
+            if event.code != KeyCode::Tab {
+                self.completion = None;
+            }
+            if !matches!(
+                (event.modifiers, event.code),
+                (KeyModifiers::CONTROL, KeyCode::Char('y')) | (KeyModifiers::ALT, KeyCode::Char('y'))
+            ) {
+                self.last_yank = None;
+            }
+            if !matches!(
+                (event.modifiers, event.code),
+                (KeyModifiers::CONTROL, KeyCode::Char('w'))
+                    | (KeyModifiers::ALT, KeyCode::Char('d'))
+                    | (KeyModifiers::CONTROL, KeyCode::Char('u'))
+                    | (KeyModifiers::CONTROL, KeyCode::Char('k'))
+            ) {
+                self.kill_ring.last_kill = None;
+            }
+
             // TODO: Functionize most of these match arms
             match (event.modifiers, event.code) {
                 (KeyModifiers::NONE | KeyModifiers::SHIFT, KeyCode::Char(c)) => self.insert_char(c)?,
+                (KeyModifiers::NONE, KeyCode::Tab) => self.handle_tab(context)?,
+                (KeyModifiers::CONTROL, KeyCode::Char('w')) => self.kill_word_before()?,
+                (KeyModifiers::ALT, KeyCode::Char('d')) => self.kill_word_after()?,
+                (KeyModifiers::CONTROL, KeyCode::Char('u')) => self.kill_to_line_start()?,
+                (KeyModifiers::CONTROL, KeyCode::Char('k')) => self.kill_to_line_end()?,
+                (KeyModifiers::CONTROL, KeyCode::Char('y')) => self.yank()?,
+                (KeyModifiers::ALT, KeyCode::Char('y')) => self.yank_pop()?,
                 (KeyModifiers::NONE, KeyCode::Backspace) => {
                     if self.cursor_coord != 0 {
                         self.backspace_char()?;
@@ -102,16 +646,33 @@ impl Console {
                 }
                 (KeyModifiers::NONE, KeyCode::Left) => {
                     if self.cursor_coord != 0 {
-                        self.move_cursor_left()?;
-                        self.cursor_coord -= 1;
+                        let start = prev_grapheme_boundary(&self.line_buffer, self.cursor_coord);
+                        let width = display_width(&self.line_buffer, start, self.cursor_coord);
+                        self.move_cursor_left(width)?;
+                        self.cursor_coord = start;
                     }
                 }
                 (KeyModifiers::NONE, KeyCode::Right) => {
                     if self.cursor_coord != self.line_buffer.len() {
-                        self.move_cursor_right()?;
-                        self.cursor_coord += 1;
+                        let end = next_grapheme_boundary(&self.line_buffer, self.cursor_coord);
+                        let width = display_width(&self.line_buffer, self.cursor_coord, end);
+                        self.move_cursor_right(width)?;
+                        self.cursor_coord = end;
+                    } else {
+                        self.accept_hint()?;
+                    }
+                }
+                (KeyModifiers::CONTROL, KeyCode::Char('e')) => {
+                    if self.cursor_coord == self.line_buffer.len() {
+                        self.accept_hint()?;
+                    } else {
+                        self.move_cursor_right_to(self.line_buffer.len())?;
                     }
                 }
+                (KeyModifiers::ALT, KeyCode::Char('f')) => self.accept_hint_word()?,
+                (KeyModifiers::NONE, KeyCode::Up) => self.history_prev()?,
+                (KeyModifiers::NONE, KeyCode::Down) => self.history_next()?,
+                (KeyModifiers::CONTROL, KeyCode::Char('r')) => self.start_reverse_search()?,
                 (KeyModifiers::NONE, KeyCode::Enter) => {
                     if !self.line_buffer.is_empty() {
                         return Ok(ReplAction::Return);
@@ -127,29 +688,402 @@ impl Console {
         Ok(ReplAction::Ignore)
     }
 
-    // Moves the cursor to the right, wrapping to the next line if necessary
-    fn move_cursor_right(&mut self) -> Result<()> {
+    // Handles a key event while a Ctrl-R reverse-i-search is active, building up the search
+    // pattern and stepping through matches instead of editing the line buffer directly
+    fn handle_search_event(&mut self, event: KeyEvent, context: &Context) -> Result<ReplAction> {
+        match (event.modifiers, event.code) {
+            (KeyModifiers::CONTROL, KeyCode::Char('r')) => self.search_next_match()?,
+            (KeyModifiers::NONE | KeyModifiers::SHIFT, KeyCode::Char(c)) => self.search_push_char(c)?,
+            (KeyModifiers::NONE, KeyCode::Backspace) => self.search_pop_char()?,
+            (KeyModifiers::NONE, KeyCode::Right) | (KeyModifiers::NONE, KeyCode::Left) => self.accept_search(context)?,
+            (KeyModifiers::NONE, KeyCode::Enter) => {
+                self.accept_search(context)?;
+                if !self.line_buffer.is_empty() {
+                    return Ok(ReplAction::Return);
+                }
+            }
+            (KeyModifiers::CONTROL, KeyCode::Char('c')) => self.cancel_search(context)?,
+            _ => (),
+        }
+
+        Ok(ReplAction::Ignore)
+    }
+
+    // Begins an incremental reverse history search, preserving the current buffer in case it is cancelled
+    fn start_reverse_search(&mut self) -> Result<()> {
+        self.search = Some(ReverseSearch {
+            pattern: String::new(),
+            match_index: None,
+            original_line: self.line_buffer.clone(),
+        });
+
+        self.redraw_search()
+    }
+
+    // Appends to the search pattern and re-searches from the most recent history entry
+    fn search_push_char(&mut self, c: char) -> Result<()> {
+        if let Some(search) = &mut self.search {
+            search.pattern.push(c);
+            search.match_index = self.history.search_before(self.history.len(), &search.pattern);
+        }
+
+        self.redraw_search()
+    }
+
+    fn search_pop_char(&mut self) -> Result<()> {
+        if let Some(search) = &mut self.search {
+            search.pattern.pop();
+            search.match_index = self.history.search_before(self.history.len(), &search.pattern);
+        }
+
+        self.redraw_search()
+    }
+
+    // Steps to the next (older) match for the current search pattern, wrapping nowhere (stops at the oldest match)
+    fn search_next_match(&mut self) -> Result<()> {
+        if let Some(search) = &mut self.search {
+            let before = search.match_index.unwrap_or(self.history.len());
+            if let Some(index) = self.history.search_before(before, &search.pattern) {
+                search.match_index = Some(index);
+            }
+        }
+
+        self.redraw_search()
+    }
+
+    // Accepts the currently matched history entry into the line buffer and ends the search
+    fn accept_search(&mut self, context: &Context) -> Result<()> {
+        if let Some(search) = self.search.take() {
+            let accepted = search
+                .match_index
+                .and_then(|index| self.history.get(index))
+                .cloned()
+                .unwrap_or(search.original_line);
+
+            self.line_buffer = accepted;
+            self.cursor_coord = self.line_buffer.len();
+            self.recompute_hint();
+            self.redraw_line(context)?;
+        }
+
+        Ok(())
+    }
+
+    // Cancels the search, restoring whatever was in the buffer before Ctrl-R was pressed
+    fn cancel_search(&mut self, context: &Context) -> Result<()> {
+        if let Some(search) = self.search.take() {
+            self.line_buffer = search.original_line;
+            self.cursor_coord = self.line_buffer.len();
+            self.recompute_hint();
+            self.redraw_line(context)?;
+        }
+
+        Ok(())
+    }
+
+    // Redraws the current terminal line as the reverse-i-search prompt with its matched entry
+    fn redraw_search(&mut self) -> Result<()> {
+        if let Some(search) = &self.search {
+            let matched = search
+                .match_index
+                .and_then(|index| self.history.get(index))
+                .cloned()
+                .unwrap_or_default();
+
+            queue!(
+                self.stdout,
+                cursor::MoveToColumn(0),
+                Clear(ClearType::CurrentLine),
+                Print(format!("(reverse-i-search)'{}': {}", search.pattern, matched)),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    // Replaces the line buffer wholesale (used by history navigation and search) and redraws it
+    fn set_buffer(&mut self, text: String) -> Result<()> {
+        self.move_cursor_left_to(0)?;
+
+        queue!(self.stdout, Clear(ClearType::UntilNewLine))?;
+        self.line_buffer = text;
+        queue!(self.stdout, Print(self.line_buffer.replace('\n', "␤")))?;
+        self.cursor_coord = self.line_buffer.len();
+        self.recompute_display_col()?;
+        self.recompute_hint();
+
+        Ok(())
+    }
+
+    // Walks to the previous (older) history entry, stashing the in-progress line on first press
+    fn history_prev(&mut self) -> Result<()> {
+        if self.history.len() == 0 {
+            return Ok(());
+        }
+
+        let prev_index = match self.history_cursor {
+            Some(0) => return Ok(()),
+            Some(index) => index - 1,
+            None => self.history.len() - 1,
+        };
+
+        self.history_cursor = Some(prev_index);
+        let entry = self.history.get(prev_index).cloned().unwrap_or_default();
+        self.set_buffer(entry)
+    }
+
+    // Walks to the next (newer) history entry, returning to an empty buffer past the newest entry
+    fn history_next(&mut self) -> Result<()> {
+        match self.history_cursor {
+            Some(index) if index + 1 < self.history.len() => {
+                self.history_cursor = Some(index + 1);
+                let entry = self.history.get(index + 1).cloned().unwrap_or_default();
+                self.set_buffer(entry)
+            }
+            Some(_) => {
+                self.history_cursor = None;
+                self.set_buffer(String::new())
+            }
+            None => Ok(()),
+        }
+    }
+
+    // Completes the word at the cursor. A single candidate is inserted outright; multiple
+    // candidates insert their longest common prefix, and a second consecutive Tab lists them
+    fn handle_tab(&mut self, context: &Context) -> Result<()> {
+        let completer = DefaultCompleter::new(context.env().CWD().clone());
+        let (start, candidates) = completer.complete(&self.line_buffer, self.cursor_coord);
+
+        if candidates.is_empty() {
+            self.completion = None;
+            return Ok(());
+        }
+
+        if candidates.len() == 1 {
+            self.replace_word(start, &candidates[0])?;
+            self.completion = None;
+            return Ok(());
+        }
+
+        let common_prefix = longest_common_prefix(&candidates);
+        let current_word = self.line_buffer[start..self.cursor_coord].to_string();
+        if common_prefix.len() > current_word.len() {
+            self.replace_word(start, &common_prefix)?;
+        }
+
+        let repeated_press = self
+            .completion
+            .as_ref()
+            .is_some_and(|prior| prior.start == start && prior.candidates == candidates);
+
+        if repeated_press {
+            self.print_completion_list(&candidates, context)?;
+        }
+
+        self.completion = Some(CompletionState { start, candidates });
+
+        Ok(())
+    }
+
+    // Replaces the word between `start` and the cursor with `replacement`, leaving the cursor
+    // immediately after the inserted text
+    fn replace_word(&mut self, start: usize, replacement: &str) -> Result<()> {
+        let mut new_buffer = self.line_buffer[..start].to_string();
+        new_buffer.push_str(replacement);
+        new_buffer.push_str(&self.line_buffer[self.cursor_coord..]);
+        let new_cursor = start + replacement.len();
+
+        self.move_cursor_left_to(0)?;
+
+        queue!(self.stdout, Clear(ClearType::UntilNewLine))?;
+        self.line_buffer = new_buffer;
+        queue!(self.stdout, Print(self.line_buffer.replace('\n', "␤")))?;
+        self.cursor_coord = self.line_buffer.len();
+        self.recompute_display_col()?;
+        self.recompute_hint();
+
+        self.move_cursor_left_to(new_cursor)?;
+
+        Ok(())
+    }
+
+    // Prints the candidate list in columns sized to the terminal width below the prompt,
+    // then redraws the prompt and buffer so editing can continue
+    fn print_completion_list(&mut self, candidates: &[String], context: &Context) -> Result<()> {
+        let term_width = terminal::size()?.0 as usize;
+        let col_width = candidates.iter().map(|c| UnicodeWidthStr::width(c.as_str())).max().unwrap_or(0) + 2;
+        let columns = (term_width / col_width.max(1)).max(1);
+
+        queue!(self.stdout, MoveToNextLine(1))?;
+        for chunk in candidates.chunks(columns) {
+            let line: String = chunk.iter().map(|c| format!("{:<width$}", c, width = col_width)).collect();
+            queue!(self.stdout, Print(line), MoveToNextLine(1))?;
+        }
+
+        self.print_prompt(context)?;
+        queue!(self.stdout, Print(self.line_buffer.replace('\n', "␤")))?;
+        self.reposition_cursor_after_redraw()?;
+
+        Ok(())
+    }
+
+    // Ctrl-W: kills the word immediately before the cursor
+    fn kill_word_before(&mut self) -> Result<()> {
+        let start = word_start_before(&self.line_buffer, self.cursor_coord);
+        if start == self.cursor_coord {
+            return Ok(());
+        }
+
+        let end = self.cursor_coord;
+        self.move_cursor_left_to(start)?;
+        let killed: String = self.line_buffer.drain(start..end).collect();
+        self.kill_ring.kill(killed, KillDirection::Backward);
+        self.recompute_hint();
+
+        self.print_buffer_section(true)
+    }
+
+    // Alt-D: kills the word immediately after the cursor
+    fn kill_word_after(&mut self) -> Result<()> {
+        let end = word_end_after(&self.line_buffer, self.cursor_coord);
+        if end == self.cursor_coord {
+            return Ok(());
+        }
+
+        let killed: String = self.line_buffer.drain(self.cursor_coord..end).collect();
+        self.kill_ring.kill(killed, KillDirection::Forward);
+        self.recompute_hint();
+
+        self.print_buffer_section(true)
+    }
+
+    // Ctrl-U: kills from the start of the line up to the cursor
+    fn kill_to_line_start(&mut self) -> Result<()> {
+        if self.cursor_coord == 0 {
+            return Ok(());
+        }
+
+        let end = self.cursor_coord;
+        self.move_cursor_left_to(0)?;
+        let killed: String = self.line_buffer.drain(0..end).collect();
+        self.kill_ring.kill(killed, KillDirection::Backward);
+        self.recompute_hint();
+
+        self.print_buffer_section(true)
+    }
+
+    // Ctrl-K: kills from the cursor to the end of the line
+    fn kill_to_line_end(&mut self) -> Result<()> {
+        if self.cursor_coord == self.line_buffer.len() {
+            return Ok(());
+        }
+
+        let killed: String = self.line_buffer.drain(self.cursor_coord..).collect();
+        self.kill_ring.kill(killed, KillDirection::Forward);
+        self.recompute_hint();
+
+        self.print_buffer_section(true)
+    }
+
+    // Ctrl-Y: yanks the most recently killed text in at the cursor
+    fn yank(&mut self) -> Result<()> {
+        let Some(text) = self.kill_ring.slots.back().cloned() else { return Ok(()) };
+        let start = self.cursor_coord;
+
+        self.line_buffer.insert_str(start, &text);
+        self.recompute_hint();
+        self.print_buffer_section(false)?;
+        self.move_cursor_right_to(start + text.len())?;
+
+        self.last_yank = Some((start, self.cursor_coord, self.kill_ring.slots.len() - 1));
+
+        Ok(())
+    }
+
+    // Alt-Y: immediately after a yank, replaces the yanked text with the previous ring entry
+    fn yank_pop(&mut self) -> Result<()> {
+        let Some((start, end, ring_index)) = self.last_yank else { return Ok(()) };
+        if self.kill_ring.slots.is_empty() {
+            return Ok(());
+        }
+
+        let prev_index = if ring_index == 0 { self.kill_ring.slots.len() - 1 } else { ring_index - 1 };
+        let replacement = self.kill_ring.slots[prev_index].clone();
+
+        self.move_cursor_left_to(start)?;
+        self.line_buffer.drain(start..end);
+
+        self.line_buffer.insert_str(start, &replacement);
+        self.recompute_hint();
+        self.print_buffer_section(false)?;
+        self.move_cursor_right_to(start + replacement.len())?;
+
+        self.last_yank = Some((start, self.cursor_coord, prev_index));
+
+        Ok(())
+    }
+
+    // Moves the cursor right by `columns` terminal columns, wrapping to the next line whenever
+    // the right edge is hit. Starts from the tracked `display_col` rather than querying the
+    // terminal, since `cursor::position()` would contend with the event-reader thread (see
+    // the `display_col` field doc)
+    fn move_cursor_right(&mut self, columns: usize) -> Result<()> {
         let x_size = terminal::size()?.0;
-        let x_pos = cursor::position()?.0;
+        let mut x_pos = self.display_col;
 
-        if x_pos == x_size - 1 {
-            queue!(self.stdout, cursor::MoveToNextLine(1))?;
-        } else {
-            queue!(self.stdout, cursor::MoveRight(1))?;
+        for _ in 0..columns {
+            if x_pos == x_size - 1 {
+                queue!(self.stdout, cursor::MoveToNextLine(1))?;
+                x_pos = 0;
+            } else {
+                queue!(self.stdout, cursor::MoveRight(1))?;
+                x_pos += 1;
+            }
         }
 
+        self.display_col = x_pos;
         Ok(())
     }
 
-    // Moves the cursor to the left, wrapping to the previous line if necessary
-    fn move_cursor_left(&mut self) -> Result<()> {
+    // Moves the cursor left by `columns` terminal columns, wrapping to the previous line
+    // whenever the left edge is hit
+    fn move_cursor_left(&mut self, columns: usize) -> Result<()> {
         let x_size = terminal::size()?.0;
-        let x_pos = cursor::position()?.0;
+        let mut x_pos = self.display_col;
 
-        if x_pos == 0 {
-            queue!(self.stdout, cursor::MoveToPreviousLine(1), cursor::MoveRight(x_size - 1))?;
-        } else {
-            queue!(self.stdout, cursor::MoveLeft(1))?;
+        for _ in 0..columns {
+            if x_pos == 0 {
+                queue!(self.stdout, cursor::MoveToPreviousLine(1), cursor::MoveRight(x_size - 1))?;
+                x_pos = x_size - 1;
+            } else {
+                queue!(self.stdout, cursor::MoveLeft(1))?;
+                x_pos -= 1;
+            }
+        }
+
+        self.display_col = x_pos;
+        Ok(())
+    }
+
+    // Walks the cursor left, one grapheme cluster at a time, until it reaches byte offset `target`
+    fn move_cursor_left_to(&mut self, target: usize) -> Result<()> {
+        while self.cursor_coord > target {
+            let start = prev_grapheme_boundary(&self.line_buffer, self.cursor_coord);
+            let width = display_width(&self.line_buffer, start, self.cursor_coord);
+            self.move_cursor_left(width)?;
+            self.cursor_coord = start;
+        }
+
+        Ok(())
+    }
+
+    // Walks the cursor right, one grapheme cluster at a time, until it reaches byte offset `target`
+    fn move_cursor_right_to(&mut self, target: usize) -> Result<()> {
+        while self.cursor_coord < target {
+            let end = next_grapheme_boundary(&self.line_buffer, self.cursor_coord);
+            let width = display_width(&self.line_buffer, self.cursor_coord, end);
+            self.move_cursor_right(width)?;
+            self.cursor_coord = end;
         }
 
         Ok(())
@@ -160,23 +1094,48 @@ impl Console {
         // Insert the char and update the buffer after the cursor
         self.line_buffer.insert(self.cursor_coord, char);
         self.print_buffer_section(false)?;
-        self.cursor_coord += 1;
-        // Move the cursor right so the text does not get overwritten upon the next insertion
-        self.move_cursor_right()?;
+        self.cursor_coord += char.len_utf8();
+        // Move the cursor right by the glyph's display width so wide characters don't get overwritten
+        let width = char.width().unwrap_or(1);
+        self.move_cursor_right(width)?;
+        self.recompute_hint();
+        // Redraw from the new cursor position so the recomputed hint is shown at end-of-line
+        self.print_buffer_section(false)?;
 
         Ok(())
     }
 
-    // Removes the character immediately preceding the cursor position from the line buffer
+    // Removes the grapheme cluster immediately preceding the cursor position from the line buffer
     fn backspace_char(&mut self) -> Result<()> {
-        self.cursor_coord -= 1;
-        self.line_buffer.remove(self.cursor_coord);
-        self.move_cursor_left()?;
+        let start = prev_grapheme_boundary(&self.line_buffer, self.cursor_coord);
+        let width = display_width(&self.line_buffer, start, self.cursor_coord);
+
+        self.line_buffer.drain(start..self.cursor_coord);
+        self.cursor_coord = start;
+        self.move_cursor_left(width)?;
+        self.recompute_hint();
         self.print_buffer_section(true)?;
 
         Ok(())
     }
 
+    // Inserts an entire paste as a single batch instead of letting each character trigger its
+    // own redraw, and keeps embedded newlines from being mistaken for an Enter keypress
+    fn handle_paste(&mut self, text: &str) -> Result<()> {
+        self.completion = None;
+        self.last_yank = None;
+
+        let normalized = text.replace("\r\n", "\n").replace('\r', "\n");
+        let start = self.cursor_coord;
+
+        self.line_buffer.insert_str(start, &normalized);
+        self.recompute_hint();
+        self.print_buffer_section(false)?;
+        self.move_cursor_right_to(start + normalized.len())?;
+
+        Ok(())
+    }
+
     // Prints a section of the line buffer starting from the cursor position
     fn print_buffer_section(&mut self, deletion_mode: bool) -> Result<()> {
         // If deleting a character, print a space at the end of the buffer to prevent
@@ -188,13 +1147,71 @@ impl Console {
             false => "",
         };
 
-        queue!(
-            self.stdout,
-            cursor::SavePosition,
-            Print(&self.line_buffer[self.cursor_coord..]),
-            Print(deletion_char),
-            cursor::RestorePosition,
-        )?;
+        // Only show the hint once there is nothing left of the buffer to type over
+        let at_end = self.cursor_coord == self.line_buffer.len();
+        let hint = if at_end { self.hint.clone() } else { None };
+
+        // A literal newline would move the real terminal cursor without a carriage return,
+        // desyncing it from the column math below, so pasted newlines are shown as a marker
+        let tail = self.line_buffer[self.cursor_coord..].replace('\n', "␤");
+
+        queue!(self.stdout, cursor::SavePosition, Print(tail), Print(deletion_char))?;
+
+        // Clear to end-of-line whenever an edit could have changed the hint, not only when a
+        // new one is drawn: an edit that makes the hint disappear entirely (e.g. the buffer no
+        // longer matches any history entry) would otherwise leave the old dimmed suffix on screen
+        if at_end {
+            queue!(self.stdout, Clear(ClearType::UntilNewLine))?;
+            if let Some(suggestion) = hint {
+                queue!(self.stdout, Print(suggestion.dark_grey()))?;
+            }
+        }
+
+        queue!(self.stdout, cursor::RestorePosition)?;
+
+        Ok(())
+    }
+
+    // Recomputes the inline history suggestion for the current line buffer
+    fn recompute_hint(&mut self) {
+        self.hint = self.history.suggestion(&self.line_buffer);
+    }
+
+    // Right-arrow/Ctrl-E at end-of-line: accepts the full suggestion into the buffer
+    fn accept_hint(&mut self) -> Result<()> {
+        let Some(hint) = self.hint.clone() else { return Ok(()) };
+        if hint.is_empty() {
+            return Ok(());
+        }
+
+        self.line_buffer.push_str(&hint);
+        self.hint = None;
+        self.print_buffer_section(false)?;
+        self.move_cursor_right_to(self.line_buffer.len())?;
+
+        self.recompute_hint();
+        self.print_buffer_section(false)?;
+
+        Ok(())
+    }
+
+    // Alt-F: accepts only the next word of the suggestion into the buffer
+    fn accept_hint_word(&mut self) -> Result<()> {
+        let Some(hint) = self.hint.clone() else { return Ok(()) };
+        if hint.is_empty() {
+            return Ok(());
+        }
+
+        let word_len = word_end_after(&hint, 0);
+        let accepted_word = hint[..word_len].to_string();
+
+        self.line_buffer.push_str(&accepted_word);
+        self.hint = None;
+        self.print_buffer_section(false)?;
+        self.move_cursor_right_to(self.line_buffer.len())?;
+
+        self.recompute_hint();
+        self.print_buffer_section(false)?;
 
         Ok(())
     }
@@ -235,11 +1252,33 @@ impl Console {
         Ok(())
     }
 
-    // Queues the prompt to be printed
+    // Queues the prompt to be printed, and refreshes `prompt_width`/`display_col` from the
+    // width of its final row (the row the buffer is printed on)
     fn print_prompt(&mut self, context: &Context) -> Result<()> {
-        queue!(self.stdout, Print(generate_prompt(context)))?;
+        let prompt = generate_prompt(context);
+        self.prompt_width = prompt.rsplit("\r\n").next().map(visible_width).unwrap_or(0);
+        self.display_col = self.prompt_width as u16;
+
+        queue!(self.stdout, Print(prompt))?;
         Ok(())
     }
+
+    // Recomputes `display_col` for the current `cursor_coord`, from the prompt's width plus
+    // the cumulative glyph width of everything in the buffer before the cursor, wrapped at the
+    // terminal width. Used after a redraw that prints the buffer wholesale rather than moving
+    // the cursor incrementally, so there is no running `display_col` to build on
+    fn recompute_display_col(&mut self) -> Result<()> {
+        let term_width = (terminal::size()?.0 as usize).max(1);
+        let buffer_width = self.display_width_upto(self.cursor_coord);
+
+        self.display_col = ((self.prompt_width + buffer_width) % term_width) as u16;
+        Ok(())
+    }
+
+    // Sums the terminal column width of each grapheme cluster in `line_buffer[..upto]`
+    fn display_width_upto(&self, upto: usize) -> usize {
+        self.line_buffer[..upto].graphemes(true).map(|g| UnicodeWidthStr::width(g).max(1)).sum()
+    }
 }
 
 // Generates the prompt string used by the REPL